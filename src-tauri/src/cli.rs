@@ -242,15 +242,16 @@ pub async fn run_cli() -> Result<()> {
     // Initialize components
     let database = Database::new(&config.database_path().to_string_lossy()).await?;
     let ai_processor = AIProcessor::new(&config).await?;
+    let shared_config = std::sync::Arc::new(tokio::sync::RwLock::new(config.clone()));
     let indexer = FileIndexer::new(
         std::sync::Arc::new(tokio::sync::RwLock::new(database)),
-        std::sync::Arc::new(tokio::sync::RwLock::new(config.clone()))
+        shared_config.clone()
     ).await?;
     
     let search_engine = SearchEngine::new(
         std::sync::Arc::new(tokio::sync::RwLock::new(database)),
         std::sync::Arc::new(tokio::sync::RwLock::new(ai_processor)),
-        std::sync::Arc::new(tokio::sync::RwLock::new(config.clone())),
+        shared_config,
         std::sync::Arc::new(tokio::sync::RwLock::new(indexer))
     ).await?;
     
@@ -1,17 +1,56 @@
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use anyhow::{Result, Context};
 use serde::{Deserialize, Serialize};
 use tracing::{info, debug, warn, error};
 use dirs;
 
+use crate::exclusions::ExclusionMatcher;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "ConfigFile")]
 pub struct Config {
     pub search_settings: SearchSettings,
     pub ui_settings: UISettings,
-    pub indexing_settings: IndexingSettings,
+    // Private so exclusion rules can only change through methods that
+    // recompile the matcher
+    indexing_settings: IndexingSettings,
     pub ai_settings: AISettings,
     pub cloud_settings: CloudSettings,
-    pub paths: PathSettings,
+    paths: PathSettings,
+    #[serde(skip)]
+    exclusions: Arc<ExclusionMatcher>,
+}
+
+/// On-disk shape of [`Config`]. Deserializing goes through this so the
+/// exclusion matcher is always compiled from the loaded rules.
+///
+/// Must mirror the serialized fields of [`Config`], including any serde
+/// attributes, or loading will silently diverge from saving.
+#[derive(Deserialize)]
+struct ConfigFile {
+    search_settings: SearchSettings,
+    ui_settings: UISettings,
+    indexing_settings: IndexingSettings,
+    ai_settings: AISettings,
+    cloud_settings: CloudSettings,
+    paths: PathSettings,
+}
+
+impl From<ConfigFile> for Config {
+    fn from(file: ConfigFile) -> Self {
+        let exclusions = Self::compile_exclusions(&file.paths, &file.indexing_settings);
+
+        Self {
+            search_settings: file.search_settings,
+            ui_settings: file.ui_settings,
+            indexing_settings: file.indexing_settings,
+            ai_settings: file.ai_settings,
+            cloud_settings: file.cloud_settings,
+            paths: file.paths,
+            exclusions,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,8 +89,8 @@ pub struct IndexingSettings {
     pub watch_file_changes: bool,
     pub index_file_content: bool,
     pub max_file_size_mb: u64,
-    pub excluded_extensions: Vec<String>,
-    pub included_extensions: Vec<String>,
+    excluded_extensions: Vec<String>,
+    included_extensions: Vec<String>,
     pub indexing_threads: usize,
     pub index_update_interval_ms: u64,
 }
@@ -87,7 +126,7 @@ pub enum CloudProvider {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PathSettings {
     pub search_paths: Vec<String>,
-    pub excluded_paths: Vec<String>,
+    excluded_paths: Vec<String>,
     pub database_path: PathBuf,
     pub cache_path: PathBuf,
     pub logs_path: PathBuf,
@@ -107,7 +146,7 @@ impl Default for Config {
             .unwrap_or_else(|| home_dir.join(".cache"))
             .join("omniosearch");
 
-        Self {
+        ConfigFile {
             search_settings: SearchSettings {
                 max_results: 1000,
                 fuzzy_threshold: 0.6,
@@ -178,6 +217,7 @@ impl Default for Config {
                 models_path: data_dir.join("models"),
             },
         }
+        .into()
     }
 }
 
@@ -239,6 +279,10 @@ impl Config {
     }
 
     // Getter methods for easy access
+    pub fn indexing_settings(&self) -> &IndexingSettings {
+        &self.indexing_settings
+    }
+
     pub fn database_path(&self) -> &Path {
         &self.paths.database_path
     }
@@ -263,27 +307,30 @@ impl Config {
         &self.paths.excluded_paths
     }
 
+    /// Shared handle to the compiled exclusion rules. Clones of this config
+    /// share the same matcher until the exclusions are changed.
+    pub fn exclusions(&self) -> Arc<ExclusionMatcher> {
+        self.exclusions.clone()
+    }
+
+    fn compile_exclusions(paths: &PathSettings, indexing: &IndexingSettings) -> Arc<ExclusionMatcher> {
+        Arc::new(ExclusionMatcher::new(
+            &paths.excluded_paths,
+            &indexing.excluded_extensions,
+            &indexing.included_extensions,
+        ))
+    }
+
+    fn rebuild_exclusions(&mut self) {
+        self.exclusions = Self::compile_exclusions(&self.paths, &self.indexing_settings);
+    }
+
     pub fn is_path_excluded(&self, path: &str) -> bool {
-        let path_lower = path.to_lowercase();
-        
-        for excluded in &self.paths.excluded_paths {
-            if path_lower.starts_with(&excluded.to_lowercase()) {
-                return true;
-            }
-        }
-        
-        false
+        self.exclusions.is_path_excluded(path)
     }
 
     pub fn is_extension_excluded(&self, extension: &str) -> bool {
-        if self.indexing_settings.excluded_extensions.is_empty() {
-            return false;
-        }
-        
-        let ext_lower = extension.to_lowercase();
-        self.indexing_settings.excluded_extensions
-            .iter()
-            .any(|excluded| excluded.to_lowercase() == ext_lower)
+        self.exclusions.is_extension_excluded(extension)
     }
 
     pub fn should_index_file(&self, path: &str, size: u64) -> bool {
@@ -306,12 +353,7 @@ impl Config {
                 }
                 
                 // If included_extensions is not empty, only index those extensions
-                if !self.indexing_settings.included_extensions.is_empty() {
-                    let ext_lower = ext_str.to_lowercase();
-                    return self.indexing_settings.included_extensions
-                        .iter()
-                        .any(|included| included.to_lowercase() == ext_lower);
-                }
+                return self.exclusions.is_extension_included(ext_str);
             }
         }
         
@@ -383,13 +425,16 @@ impl Config {
     pub fn add_excluded_path(&mut self, path: String) {
         if !self.paths.excluded_paths.contains(&path) {
             self.paths.excluded_paths.push(path);
+            self.rebuild_exclusions();
         }
     }
 
     pub fn remove_excluded_path(&mut self, path: &str) {
         self.paths.excluded_paths.retain(|p| p != path);
+        self.rebuild_exclusions();
     }
 
+
     pub fn toggle_cloud_provider(&mut self, provider: CloudProvider) {
         if let Some(pos) = self.cloud_settings.enabled_providers
             .iter()
@@ -400,3 +445,57 @@ impl Config {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_extensions(excluded: &[&str], included: &[&str]) -> Config {
+        let mut config = Config::default();
+        config.indexing_settings.excluded_extensions = excluded.iter().map(|e| e.to_string()).collect();
+        config.indexing_settings.included_extensions = included.iter().map(|e| e.to_string()).collect();
+        config.rebuild_exclusions();
+        config
+    }
+
+    #[test]
+    fn should_index_file_with_empty_include_list() {
+        let config = config_with_extensions(&["log"], &[]);
+
+        assert!(config.should_index_file("/srv/notes.txt", 0));
+        assert!(config.should_index_file("/srv/Makefile", 0));
+        assert!(!config.should_index_file("/srv/app.LOG", 0));
+        assert!(!config.should_index_file("/proc/1/status.txt", 0));
+    }
+
+    #[test]
+    fn should_index_file_with_include_list() {
+        let config = config_with_extensions(&["log"], &["RS", "md"]);
+
+        assert!(config.should_index_file("/srv/main.rs", 0));
+        assert!(config.should_index_file("/srv/README.MD", 0));
+        assert!(!config.should_index_file("/srv/notes.txt", 0));
+        // Files without an extension are not filtered by the include list
+        assert!(config.should_index_file("/srv/Makefile", 0));
+    }
+
+    #[test]
+    fn should_index_file_excluded_extension_wins_over_include_list() {
+        let config = config_with_extensions(&["log"], &["log", "rs"]);
+
+        assert!(!config.should_index_file("/srv/app.log", 0));
+        assert!(config.should_index_file("/srv/main.rs", 0));
+    }
+
+    #[test]
+    fn deserialized_config_compiles_exclusions() {
+        let mut config = Config::default();
+        config.add_excluded_path("/srv/private".to_string());
+
+        let toml = toml::to_string(&config).unwrap();
+        let loaded: Config = toml::from_str(&toml).unwrap();
+
+        assert!(loaded.is_path_excluded("/srv/private/key.pem"));
+        assert!(!loaded.should_index_file("/srv/app.log", 0));
+    }
+}
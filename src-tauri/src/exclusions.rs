@@ -0,0 +1,340 @@
+use std::collections::HashSet;
+use std::path::Path;
+use aho_corasick::{AhoCorasick, Anchored, Input, MatchKind, StartKind};
+use tracing::{debug, warn};
+
+/// Precompiled exclusion rules shared by the indexer, the file watcher and
+/// filesystem search. Built once from the configuration and rebuilt whenever
+/// the excluded paths or extensions change.
+#[derive(Debug)]
+pub struct ExclusionMatcher {
+    path_matcher: Option<AhoCorasick>,
+    rules: Vec<String>,
+    excluded_paths: Vec<String>,
+    excluded_extensions: ExtensionSet,
+    included_extensions: ExtensionSet,
+}
+
+impl ExclusionMatcher {
+    pub fn new(
+        excluded_paths: &[String],
+        excluded_extensions: &[String],
+        included_extensions: &[String],
+    ) -> Self {
+        let rules = excluded_paths.to_vec();
+        let excluded_paths: Vec<String> = excluded_paths
+            .iter()
+            .map(|p| p.to_lowercase())
+            .collect();
+
+        // Anchored search turns the automaton into a multi-prefix matcher,
+        // so a single pass over the path replaces one `starts_with` per rule
+        let path_matcher = match AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostFirst)
+            .start_kind(StartKind::Anchored)
+            .ascii_case_insensitive(true)
+            .build(&excluded_paths)
+        {
+            Ok(matcher) => Some(matcher),
+            Err(e) => {
+                warn!("⚠️ Failed to compile path exclusions, falling back to linear scan: {}", e);
+                None
+            }
+        };
+
+        debug!("🚫 Compiled {} path exclusions, {} excluded extensions",
+               excluded_paths.len(), excluded_extensions.len());
+
+        Self {
+            path_matcher,
+            rules,
+            excluded_paths,
+            excluded_extensions: ExtensionSet::new(excluded_extensions),
+            included_extensions: ExtensionSet::new(included_extensions),
+        }
+    }
+
+    pub fn is_path_excluded(&self, path: &str) -> bool {
+        if self.excluded_paths.is_empty() {
+            return false;
+        }
+
+        // The automaton folds ASCII case itself; only non-ASCII paths need
+        // a full Unicode lowercase to match the rules
+        let lowered;
+        let haystack = if path.is_ascii() {
+            path
+        } else {
+            lowered = path.to_lowercase();
+            &lowered
+        };
+
+        match &self.path_matcher {
+            Some(matcher) => matcher.is_match(Input::new(haystack).anchored(Anchored::Yes)),
+            None => self.excluded_paths
+                .iter()
+                .any(|excluded| haystack
+                    .get(..excluded.len())
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(excluded))),
+        }
+    }
+
+    /// Exclusion globs for an external walker (fd, ripgrep) rooted at
+    /// `search_root`, so excluded trees are pruned instead of walked.
+    /// Returns `None` when the root itself is excluded.
+    ///
+    /// The globs are case-sensitive, so callers should still filter the
+    /// walker's output with [`is_path_excluded`](Self::is_path_excluded).
+    pub fn walker_globs(&self, search_root: &str) -> Option<Vec<String>> {
+        if self.is_path_excluded(search_root) {
+            return None;
+        }
+
+        let root = Path::new(search_root);
+        let globs = self.rules
+            .iter()
+            .filter_map(|rule| {
+                let relative = Path::new(rule).strip_prefix(root).ok()?.to_str()?;
+                if relative.is_empty() {
+                    return None;
+                }
+
+                // A rule is a plain string prefix, so `/a/.cache` also covers
+                // `/a/.cache2`; a trailing `*` keeps that within one component
+                let suffix = if rule.ends_with('/') { "/" } else { "*" };
+                Some(format!("/{}{}", escape_glob(relative), suffix))
+            })
+            .collect();
+
+        Some(globs)
+    }
+
+    pub fn is_extension_excluded(&self, extension: &str) -> bool {
+        self.excluded_extensions.contains(extension)
+    }
+
+    /// Returns `true` when the extension passes the include list. An empty
+    /// include list allows every extension.
+    pub fn is_extension_included(&self, extension: &str) -> bool {
+        self.included_extensions.is_empty() || self.included_extensions.contains(extension)
+    }
+}
+
+fn escape_glob(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for c in path.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '{' | '}' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Case-insensitive extension set with a bloom filter in front of the hash
+/// lookup. A miss costs one FNV pass and no allocation; a hit only allocates
+/// when the extension is not already lowercase.
+#[derive(Debug, Default)]
+struct ExtensionSet {
+    bloom: [u64; 4],
+    extensions: HashSet<String>,
+}
+
+impl ExtensionSet {
+    fn new(extensions: &[String]) -> Self {
+        let mut set = Self::default();
+
+        for extension in extensions {
+            let ext_lower = extension.to_lowercase();
+            for bit in Self::bloom_bits(&ext_lower) {
+                set.bloom[bit / 64] |= 1 << (bit % 64);
+            }
+            set.extensions.insert(ext_lower);
+        }
+
+        set
+    }
+
+    fn is_empty(&self) -> bool {
+        self.extensions.is_empty()
+    }
+
+    fn contains(&self, extension: &str) -> bool {
+        if self.extensions.is_empty() {
+            return false;
+        }
+
+        // ASCII case is folded inside the hash; only non-ASCII extensions
+        // need a Unicode lowercase up front
+        let unicode_lower;
+        let probe = if extension.is_ascii() {
+            extension
+        } else {
+            unicode_lower = extension.to_lowercase();
+            &unicode_lower
+        };
+
+        let maybe_present = Self::bloom_bits(probe)
+            .iter()
+            .all(|&bit| self.bloom[bit / 64] & (1 << (bit % 64)) != 0);
+        if !maybe_present {
+            return false;
+        }
+
+        if probe.bytes().any(|b| b.is_ascii_uppercase()) {
+            self.extensions.contains(&probe.to_ascii_lowercase())
+        } else {
+            self.extensions.contains(probe)
+        }
+    }
+
+    fn bloom_bits(extension: &str) -> [usize; 2] {
+        // FNV-1a over ASCII-lowercased bytes
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in extension.bytes() {
+            hash ^= byte.to_ascii_lowercase() as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+
+        [(hash & 0xff) as usize, ((hash >> 32) & 0xff) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    // The linear scans this matcher replaced
+    fn linear_path_excluded(rules: &[String], path: &str) -> bool {
+        let path_lower = path.to_lowercase();
+        rules.iter().any(|rule| path_lower.starts_with(&rule.to_lowercase()))
+    }
+
+    fn linear_extension_in(extensions: &[String], extension: &str) -> bool {
+        let ext_lower = extension.to_lowercase();
+        extensions.iter().any(|e| e.to_lowercase() == ext_lower)
+    }
+
+    fn assert_paths_match_linear(rules: &[String], paths: &[&str]) {
+        let matcher = ExclusionMatcher::new(rules, &[], &[]);
+        for path in paths {
+            assert_eq!(
+                matcher.is_path_excluded(path),
+                linear_path_excluded(rules, path),
+                "rules {:?}, path {}", rules, path
+            );
+        }
+    }
+
+    #[test]
+    fn mixed_case_ascii_paths() {
+        let rules = strings(&["/Home/User/.Cache", "/proc"]);
+        let matcher = ExclusionMatcher::new(&rules, &[], &[]);
+
+        assert!(matcher.is_path_excluded("/home/user/.cache/thumbnails/a.png"));
+        assert!(matcher.is_path_excluded("/HOME/USER/.CACHE"));
+        assert!(matcher.is_path_excluded("/PROC/1/status"));
+        assert!(!matcher.is_path_excluded("/home/user/docs/report.pdf"));
+        assert!(!matcher.is_path_excluded("/srv/proc"));
+
+        assert_paths_match_linear(&rules, &[
+            "/home/user/.cache/x",
+            "/HOME/USER/.CACHE",
+            "/home/user/.cachedir",
+            "/home/user/.cach",
+            "/srv/proc",
+            "/Proc",
+        ]);
+    }
+
+    #[test]
+    fn non_ascii_paths() {
+        let rules = strings(&["/home/Ünïcode", "/data/ÉTÉ"]);
+        let matcher = ExclusionMatcher::new(&rules, &[], &[]);
+
+        assert!(matcher.is_path_excluded("/HOME/üNÏCODE/file.txt"));
+        assert!(matcher.is_path_excluded("/data/été/photo.jpg"));
+        assert!(!matcher.is_path_excluded("/home/unicode/file.txt"));
+
+        assert_paths_match_linear(&rules, &[
+            "/HOME/üNÏCODE/file.txt",
+            "/home/ÜNÏCODE",
+            "/home/unicode",
+            "/data/Été",
+            "/data/ete",
+            "/data/ÉT",
+        ]);
+    }
+
+    #[test]
+    fn linear_fallback_matches_automaton() {
+        let rules = strings(&["/Home/User/.Cache", "/data/ÉTÉ", "/data"]);
+        let compiled = ExclusionMatcher::new(&rules, &[], &[]);
+        let mut fallback = ExclusionMatcher::new(&rules, &[], &[]);
+        fallback.path_matcher = None;
+
+        for path in ["/home/user/.cache/x", "/HOME/USER/.CACHE", "/data/été/a", "/dat", "/srv", "/ÉTÉ"] {
+            assert_eq!(fallback.is_path_excluded(path), compiled.is_path_excluded(path), "path {}", path);
+            assert_eq!(fallback.is_path_excluded(path), linear_path_excluded(&rules, path), "path {}", path);
+        }
+    }
+
+    #[test]
+    fn empty_rule_list_excludes_nothing() {
+        let matcher = ExclusionMatcher::new(&[], &[], &[]);
+
+        assert!(!matcher.is_path_excluded("/"));
+        assert!(!matcher.is_path_excluded("/proc/1"));
+        assert!(!matcher.is_path_excluded(""));
+        assert!(!matcher.is_extension_excluded("tmp"));
+        assert!(matcher.is_extension_included("anything"));
+    }
+
+    #[test]
+    fn rule_that_prefixes_another() {
+        let rules = strings(&["/data", "/data/archive"]);
+        let reversed = strings(&["/data/archive", "/data"]);
+        let paths = [
+            "/data",
+            "/data/archive/2020.tar",
+            "/data/live",
+            "/data2/file",
+            "/dat",
+            "/srv/data",
+        ];
+
+        assert_paths_match_linear(&rules, &paths);
+        assert_paths_match_linear(&reversed, &paths);
+    }
+
+    #[test]
+    fn excluded_extensions_mixed_case() {
+        let excluded = strings(&["TMP", "Log", "swp", "Épub"]);
+        let matcher = ExclusionMatcher::new(&[], &excluded, &[]);
+
+        for extension in ["tmp", "TMP", "Tmp", "log", "LOG", "SWP", "txt", "tm", "tmpx", "Ümlaut", "épub", "ÉPUB", "epub"] {
+            assert_eq!(
+                matcher.is_extension_excluded(extension),
+                linear_extension_in(&excluded, extension),
+                "extension {}", extension
+            );
+        }
+    }
+
+    #[test]
+    fn walker_globs_prune_rules_under_root() {
+        let rules = strings(&["/home/u/.cache", "/home/u/build/", "/tmp", "/home/u/a[1]"]);
+        let matcher = ExclusionMatcher::new(&rules, &[], &[]);
+
+        assert_eq!(
+            matcher.walker_globs("/home/u"),
+            Some(strings(&["/.cache*", "/build/", "/a\\[1\\]*"]))
+        );
+        assert_eq!(matcher.walker_globs("/tmp/scratch"), None);
+        assert_eq!(matcher.walker_globs("/opt"), Some(vec![]));
+    }
+}
@@ -283,7 +283,7 @@ impl FileIndexer {
         // Check for hidden files/directories
         if let Some(file_name) = path.file_name() {
             if let Some(name_str) = file_name.to_str() {
-                if name_str.starts_with('.') && !config.indexing_settings.watch_file_changes {
+                if name_str.starts_with('.') && !config.indexing_settings().watch_file_changes {
                     return true;
                 }
             }
@@ -436,14 +436,14 @@ impl FileIndexer {
         info!("👀 Starting file system watcher...");
 
         let config = self.config.read().await;
-        if !config.indexing_settings.watch_file_changes {
+        if !config.indexing_settings().watch_file_changes {
             info!("📁 File watching is disabled in configuration");
             return Ok(());
         }
 
         let (tx, mut rx) = mpsc::unbounded_channel();
         let database = self.database.clone();
-        let config_clone = config.clone();
+        let shared_config = self.config.clone();
 
         let mut watcher = notify::recommended_watcher(move |event: Result<Event, _>| {
             if let Ok(event) = event {
@@ -466,7 +466,7 @@ impl FileIndexer {
         // Handle file system events
         tokio::spawn(async move {
             while let Some(event) = rx.recv().await {
-                Self::handle_file_event(event, &database, &config_clone).await;
+                Self::handle_file_event(event, &database, &shared_config).await;
             }
         });
 
@@ -477,21 +477,29 @@ impl FileIndexer {
     async fn handle_file_event(
         event: Event,
         database: &Arc<RwLock<Database>>,
-        config: &Config,
+        config: &Arc<RwLock<Config>>,
     ) {
         debug!("📁 File system event: {:?}", event);
 
         match event.kind {
             EventKind::Create(_) | EventKind::Modify(_) => {
                 for path in event.paths {
-                    if path.is_file() && config.should_index_file(&path.to_string_lossy(), 0) {
-                        if let Ok(entry) = Self::create_file_entry(&path, config) {
-                            let db = database.read().await;
-                            if let Err(e) = db.insert_file(&entry).await {
-                                warn!("⚠️ Failed to update index for {}: {}", path.display(), e);
-                            } else {
-                                debug!("✅ Updated index for: {}", path.display());
-                            }
+                    // Read the live config per event so exclusion changes
+                    // apply without restarting the watcher
+                    let entry = {
+                        let config = config.read().await;
+                        if !path.is_file() || !config.should_index_file(&path.to_string_lossy(), 0) {
+                            continue;
+                        }
+                        Self::create_file_entry(&path, &config)
+                    };
+
+                    if let Ok(entry) = entry {
+                        let db = database.read().await;
+                        if let Err(e) = db.insert_file(&entry).await {
+                            warn!("⚠️ Failed to update index for {}: {}", path.display(), e);
+                        } else {
+                            debug!("✅ Updated index for: {}", path.display());
                         }
                     }
                 }
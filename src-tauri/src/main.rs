@@ -11,6 +11,7 @@ mod ai;
 mod db;
 mod cloud;
 mod config;
+mod exclusions;
 mod indexer;
 
 use search::{SearchEngine, SearchQuery, SearchResult};
@@ -44,6 +45,10 @@ impl AppState {
         let ai_processor = AIProcessor::new(&config).await?;
         info!("✅ AI processor initialized with local models");
 
+        // Indexer, watcher and search engine share one config so exclusion
+        // changes reach all of them
+        let config = Arc::new(RwLock::new(config));
+
         // Initialize file indexer
         let indexer = FileIndexer::new(database.clone(), config.clone()).await?;
         info!("✅ File indexer initialized");
@@ -61,7 +66,7 @@ impl AppState {
             search_engine: Arc::new(RwLock::new(search_engine)),
             ai_processor: Arc::new(RwLock::new(ai_processor)),
             database: Arc::new(RwLock::new(database)),
-            config: Arc::new(RwLock::new(config)),
+            config,
             indexer: Arc::new(RwLock::new(indexer)),
         })
     }
@@ -11,6 +11,7 @@ use rayon::prelude::*;
 use crate::ai::AIProcessor;
 use crate::db::Database;
 use crate::config::Config;
+use crate::exclusions::ExclusionMatcher;
 use crate::indexer::FileIndexer;
 use crate::cloud;
 
@@ -195,12 +196,13 @@ impl SearchEngine {
 
         let config = self.config.read().await;
         let search_paths = config.search_paths();
+        let exclusions = config.exclusions();
         
         let mut results = Vec::new();
 
         // Use fd (find) for fast file discovery
         for search_path in search_paths {
-            let fd_results = self.fd_search(&search_path, query).await?;
+            let fd_results = self.fd_search(&search_path, query, &exclusions).await?;
             results.extend(fd_results);
         }
 
@@ -208,16 +210,41 @@ impl SearchEngine {
         Ok(results)
     }
 
-    async fn fd_search(&self, path: &str, query: &SearchQuery) -> Result<Vec<SearchResult>> {
+    async fn fd_search(
+        &self,
+        path: &str,
+        query: &SearchQuery,
+        exclusions: &ExclusionMatcher,
+    ) -> Result<Vec<SearchResult>> {
         use std::process::Command;
 
+        // Missing search paths (e.g. /opt) are skipped rather than failing
+        // the whole search
+        if !Path::new(path).is_dir() {
+            return Ok(Vec::new());
+        }
+
+        // Excluded trees are pruned by fd itself; a root under an exclusion
+        // has nothing to search
+        let exclude_globs = match exclusions.walker_globs(path) {
+            Some(globs) => globs,
+            None => return Ok(Vec::new()),
+        };
+
+        // Headroom for the post-filter below, which drops the rare hits the
+        // case-sensitive globs miss before applying the real limit
+        let fd_max_results = query.max_results.saturating_mul(2).to_string();
         let mut args = vec![
             "--type", "f",
             "--follow",
             "--absolute-path",
-            "--max-results", &query.max_results.to_string(),
+            "--max-results", &fd_max_results,
         ];
 
+        for glob in &exclude_globs {
+            args.extend(["--exclude", glob.as_str()]);
+        }
+
         if query.include_hidden {
             args.push("--hidden");
         }
@@ -239,6 +266,14 @@ impl SearchEngine {
         if output.status.success() {
             let stdout = String::from_utf8_lossy(&output.stdout);
             for line in stdout.lines() {
+                if results.len() >= query.max_results {
+                    break;
+                }
+
+                if exclusions.is_path_excluded(line) {
+                    continue;
+                }
+
                 if let Ok(metadata) = std::fs::metadata(line) {
                     let result = SearchResult {
                         path: line.to_string(),
@@ -276,11 +311,12 @@ impl SearchEngine {
 
         let config = self.config.read().await;
         let search_paths = config.search_paths();
+        let exclusions = config.exclusions();
         
         let mut results = Vec::new();
 
         for search_path in search_paths {
-            let rg_results = self.ripgrep_search(&search_path, query).await?;
+            let rg_results = self.ripgrep_search(&search_path, query, &exclusions).await?;
             results.extend(rg_results);
         }
 
@@ -288,9 +324,23 @@ impl SearchEngine {
         Ok(results)
     }
 
-    async fn ripgrep_search(&self, path: &str, query: &SearchQuery) -> Result<Vec<SearchResult>> {
+    async fn ripgrep_search(
+        &self,
+        path: &str,
+        query: &SearchQuery,
+        exclusions: &ExclusionMatcher,
+    ) -> Result<Vec<SearchResult>> {
         use std::process::Command;
 
+        if !Path::new(path).is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let exclude_globs: Vec<String> = match exclusions.walker_globs(path) {
+            Some(globs) => globs.iter().map(|glob| format!("!{}", glob)).collect(),
+            None => return Ok(Vec::new()),
+        };
+
         let mut args = vec![
             "--json",
             "--follow",
@@ -298,6 +348,10 @@ impl SearchEngine {
             "--max-filesize", "10M", // Skip large files
         ];
 
+        for glob in &exclude_globs {
+            args.extend(["--glob", glob.as_str()]);
+        }
+
         if query.include_hidden {
             args.push("--hidden");
         }
@@ -313,8 +367,10 @@ impl SearchEngine {
         args.push(&query.text);
         args.push(path);
 
+        // ripgrep anchors globs to its working directory
         let output = Command::new("rg")
             .args(&args)
+            .current_dir(path)
             .output()?;
 
         let mut results = Vec::new();
@@ -330,6 +386,10 @@ impl SearchEngine {
                             json["data"]["line_number"].as_u64(),
                             json["data"]["lines"]["text"].as_str(),
                         ) {
+                            if exclusions.is_path_excluded(path) {
+                                continue;
+                            }
+
                             let matches = json["data"]["submatches"]
                                 .as_array()
                                 .unwrap_or(&vec![])